@@ -1,8 +1,152 @@
-//! Implementation of the basic Boyer-Moore string matching algorithm (without the
-//! Apostolico-Giancarlo extension).
+//! Implementation of the Boyer-Moore string matching algorithm, including an opt-in
+//! Apostolico-Giancarlo extension ([`BoyerMoore::search_ag`]) for a worst-case linear number of
+//! symbol comparisons, and opt-in case-insensitive matching ([`MatchConfig`]).
 
 use anyhow::{ensure, Context, Result};
-use std::collections::HashMap;
+use memchr::memchr;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::io::Read;
+use std::str::from_utf8;
+
+/// Approximate relative frequency of each byte value in typical English/ASCII text, indexed by
+/// byte value. Used by [`BoyerMoore::with_prefilter`] to pick a "guard" byte that is unlikely to
+/// occur in the text, so that `memchr` can skip most alignments in a single vectorized scan.
+/// Lower values are rarer.
+#[rustfmt::skip]
+const BYTE_FREQUENCY: [u32; 256] = [
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 8, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    130, 5, 5, 1, 1, 1, 1, 5, 5, 5, 1, 1, 5, 5, 5, 1,
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 5, 5, 1, 1, 1, 5,
+    1, 27, 5, 9, 14, 42, 7, 6, 20, 23, 1, 2, 13, 8, 22, 25,
+    6, 1, 20, 21, 30, 9, 3, 8, 1, 6, 1, 1, 1, 1, 1, 1,
+    1, 82, 15, 28, 43, 127, 22, 20, 61, 70, 2, 8, 40, 24, 67, 75,
+    19, 1, 60, 63, 91, 28, 10, 24, 2, 20, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+];
+
+/// A sequence of comparable symbols that [`BoyerMoore`] can preprocess and search over.
+///
+/// Implementing this for a sequence type lets `BoyerMoore` run over alphabets other than raw
+/// bytes, such as `char`s (for correct Unicode handling) or arbitrary token sequences.
+pub trait Searchable<T> {
+    /// Returns the number of symbols in the sequence.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the sequence has no symbols.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol at `index`.
+    fn value_at(&self, index: usize) -> T;
+
+    /// Returns an iterator over the symbols in the sequence, in order.
+    fn iter(&self) -> Box<dyn Iterator<Item = T> + '_>;
+}
+
+impl Searchable<char> for &str {
+    fn len(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn value_at(&self, index: usize) -> char {
+        self.chars().nth(index).unwrap()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = char> + '_> {
+        Box::new(self.chars())
+    }
+}
+
+impl Searchable<char> for &[char] {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn value_at(&self, index: usize) -> char {
+        self[index]
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = char> + '_> {
+        Box::new((**self).iter().copied())
+    }
+}
+
+impl Searchable<char> for Vec<char> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn value_at(&self, index: usize) -> char {
+        self[index]
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = char> + '_> {
+        Box::new(self.as_slice().iter().copied())
+    }
+}
+
+impl Searchable<u8> for &[u8] {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn value_at(&self, index: usize) -> u8 {
+        self[index]
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
+        Box::new((**self).iter().copied())
+    }
+}
+
+/// Controls how characters are folded before [`BoyerMoore`] compares them, used by
+/// [`BoyerMoore::with_match_config`].
+///
+/// Folding is applied to the pattern and alphabet once at construction time (so the
+/// bad-character and good-suffix tables are already built over the folded alphabet) and to the
+/// text once per search, which keeps the comparison loops themselves unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchConfig {
+    /// Compare characters exactly as given. This is the default, used by [`BoyerMoore::new`].
+    #[default]
+    CaseSensitive,
+    /// Fold ASCII letters to lowercase before comparing; non-ASCII characters are left alone.
+    AsciiCaseInsensitive,
+    /// Fold characters to lowercase using Unicode's simple (one-to-one) case mapping before
+    /// comparing, so non-ASCII letters such as `'É'` and `'é'` are also treated as equal.
+    UnicodeCaseFold,
+}
+
+impl MatchConfig {
+    /// Folds a single character according to this configuration.
+    fn fold(self, c: char) -> char {
+        match self {
+            Self::CaseSensitive => c,
+            Self::AsciiCaseInsensitive => c.to_ascii_lowercase(),
+            // Unicode's *simple* case folding is a one-to-one mapping: a handful of characters
+            // (e.g. 'İ', whose full lowercase mapping is the two-character "i̇") have no
+            // single-character folded form and are left unchanged rather than truncated.
+            Self::UnicodeCaseFold => {
+                let mut lower = c.to_lowercase();
+                match (lower.next(), lower.next()) {
+                    (Some(folded), None) => folded,
+                    _ => c,
+                }
+            }
+        }
+    }
+}
 
 /// Uses Z algorithm to preprocess s.
 ///
@@ -10,19 +154,17 @@ use std::collections::HashMap;
 ///
 /// # Errors
 ///
-/// Returns an error if the length of the pattern is not more than 1 character.
-fn z_array(string: &str) -> Result<Vec<usize>> {
-    let len = string.len();
-    ensure!(len > 1, "Length of string must be greater than 1");
+/// Returns an error if the length of the pattern is not more than 1 symbol.
+fn z_array<T: PartialEq>(seq: &[T]) -> Result<Vec<usize>> {
+    let len = seq.len();
+    ensure!(len > 1, "Length of pattern must be greater than 1");
 
     let mut z_arr = vec![0_usize; len];
     z_arr[0] = len;
 
-    let chars = string.as_bytes();
-
     // Initial comparison of s[1:] with prefix
     for i in 1..len {
-        if chars[i] != chars[i - 1] {
+        if seq[i] != seq[i - 1] {
             break;
         }
 
@@ -39,7 +181,7 @@ fn z_array(string: &str) -> Result<Vec<usize>> {
         // Case 1
         if k > right {
             for i in k..len {
-                if chars[i] == chars[i - k] {
+                if seq[i] == seq[i - k] {
                     z_arr[k] += 1;
                 } else {
                     break;
@@ -56,7 +198,7 @@ fn z_array(string: &str) -> Result<Vec<usize>> {
         else {
             let mut matches = 0;
             for i in right + 1..len {
-                if chars[i] != chars[i - k] {
+                if seq[i] != seq[i - k] {
                     break;
                 }
                 matches += 1;
@@ -77,10 +219,10 @@ fn z_array(string: &str) -> Result<Vec<usize>> {
 ///
 /// # Errors
 ///
-/// Returns an error if the length of the pattern is not more than 1 character.
-fn n_array(string: &str) -> Result<Vec<usize>> {
-    let mut n_arr = z_array(string.chars().rev().collect::<String>().as_str())
-        .with_context(|| format!("Failed to find Z array for string \"{}\"", string))?;
+/// Returns an error if the length of the pattern is not more than 1 symbol.
+fn n_array<T: PartialEq + Clone>(seq: &[T]) -> Result<Vec<usize>> {
+    let reversed: Vec<T> = seq.iter().rev().cloned().collect();
+    let mut n_arr = z_array(&reversed).with_context(|| "Failed to find Z array for pattern")?;
     n_arr.reverse();
     Ok(n_arr)
 }
@@ -88,8 +230,7 @@ fn n_array(string: &str) -> Result<Vec<usize>> {
 /// Compiles L' array using p and N array.
 ///
 /// `L'[i]` = largest index `j < m` such that `N[j] = |P[i:]|`.
-fn big_l_prime_array(pattern: &str, n_arr: &[usize]) -> Vec<usize> {
-    let len = pattern.len();
+fn big_l_prime_array(len: usize, n_arr: &[usize]) -> Vec<usize> {
     let mut l_prime = vec![0; len];
 
     for (j, &n_j) in n_arr.iter().enumerate() {
@@ -105,8 +246,7 @@ fn big_l_prime_array(pattern: &str, n_arr: &[usize]) -> Vec<usize> {
 /// Compiles L array using p and L' array.
 ///
 /// `L[i]` = largest index `j < m` such that `N[j] >= |P[i:]|`.
-fn big_l_array(pattern: &str, l_prime_arr: &[usize]) -> Vec<usize> {
-    let len = pattern.len();
+fn big_l_array(len: usize, l_prime_arr: &[usize]) -> Vec<usize> {
     let mut l_arr = vec![0; len];
     l_arr[1] = l_prime_arr[1];
 
@@ -141,49 +281,45 @@ fn small_l_prime_array(n_arr: &[usize]) -> Vec<usize> {
     small_l_prime_arr
 }
 
-/// Return tables needed to apply good suffix rule.
-///
-/// # Errors
-///
-/// Returns an error if the length of the pattern is not more than 1 character.
-fn good_suffix_table(pattern: &str) -> Result<(Vec<usize>, Vec<usize>, Vec<usize>)> {
-    let n_arr = n_array(pattern)?;
-    let l_prime_arr = big_l_prime_array(pattern, n_arr.as_slice());
-    let big_l_arr = big_l_array(pattern, l_prime_arr.as_slice());
-    let small_l_prime_arr = small_l_prime_array(n_arr.as_slice());
-
-    Ok((l_prime_arr, big_l_arr, small_l_prime_arr))
+/// Return tables needed to apply good suffix rule, given the pattern's precomputed N array.
+fn good_suffix_table(pattern_len: usize, n_arr: &[usize]) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let l_prime_arr = big_l_prime_array(pattern_len, n_arr);
+    let big_l_arr = big_l_array(pattern_len, l_prime_arr.as_slice());
+    let small_l_prime_arr = small_l_prime_array(n_arr);
+
+    (l_prime_arr, big_l_arr, small_l_prime_arr)
 }
 
-/// Given pattern string and list with ordered alphabet characters, create and return a dense bad
-/// character table. Table is indexed by offset then by character.
+/// Given pattern symbols and a map with ordered alphabet symbols, create and return a dense bad
+/// symbol table. Table is indexed by offset then by symbol.
 ///
 /// # Errors
 ///
-/// Returns an error if a character in the pattern is not found in the alphabet map or if the
-/// length of the pattern is not more than 1 character.
-fn dense_bad_char_table(
-    pattern: &str,
-    alpha_map: &HashMap<char, usize>,
+/// Returns an error if a symbol in the pattern is not found in the alphabet map. The length of
+/// the pattern is not checked here.
+fn dense_bad_char_table<T: Eq + Hash + Clone + Debug>(
+    pattern: &[T],
+    alpha_map: &HashMap<T, usize>,
 ) -> Result<Vec<Vec<usize>>> {
     let mut table: Vec<Vec<usize>> = Vec::new();
     let mut next = vec![0_usize; alpha_map.len()];
 
-    for (i, character) in pattern.chars().enumerate() {
+    for (i, symbol) in pattern.iter().enumerate() {
         ensure!(
-            alpha_map.contains_key(&character),
-            format!("{} not found in alphabet", character)
+            alpha_map.contains_key(symbol),
+            format!("{:?} not found in alphabet", symbol)
         );
 
         table.push(next.clone());
-        let &index = alpha_map.get(&character).unwrap();
+        let &index = alpha_map.get(symbol).unwrap();
         next[index] = i + 1;
     }
 
     Ok(table)
 }
 
-/// Encapsulates pattern and associated Boyer-Moore preprocessing.
+/// Encapsulates a pattern over a symbol alphabet `T` and its associated Boyer-Moore
+/// preprocessing.
 ///
 /// # Examples
 ///
@@ -191,65 +327,43 @@ fn dense_bad_char_table(
 /// use boyer_moore::BoyerMoore;
 /// # use anyhow::Result;
 ///
-/// fn search(pattern: &str, bm: BoyerMoore, text: &str) -> Result<Vec<usize>> {
-///     let mut occurrences = Vec::new();
+/// # fn main() -> Result<()> {
+/// let bm = BoyerMoore::new("TCTA", "ACGT")?;
+/// let occurrences = bm.find_all("GCTAGCTCTACGAGTCTA")?;
 ///
-///     let pattern = pattern.as_bytes();
-///     let text = text.as_bytes();
-///     let mut i = 0;
-///
-///     while i < text.len() - pattern.len() + 1 {
-///         let mut shift = 1;
-///         let mut mismatched = false;
-///
-///         for j in (0..pattern.len()).rev() {
-///             if pattern[j] != text[i + j] {
-///                 let skip_bc = bm.bad_char_rule(j, text[i + j] as char).unwrap();
-///                 let skip_gs = bm.good_suffix_rule(j)?;
-///                 shift = *[shift, skip_bc, skip_gs].iter().max().unwrap();
-///                 mismatched = true;
-///                 break;
-///             }
-///         }
-///
-///         if !mismatched {
-///             occurrences.push(i);
-///             let skip_gs = bm.match_skip();
-///             shift = *[shift, skip_gs].iter().max().unwrap();
-///         }
-///
-///         i += shift;
-///     }
-///
-///     Ok(occurrences)
-/// }
-///
-/// fn main() -> Result<()> {
-///     let alphabet = "ACGT";
-///     let text = "GCTAGCTCTACGAGTCTA";
-///     let pattern = "TCTA";
-///     let bm = BoyerMoore::new(pattern, alphabet)?;
-///
-///     assert_eq!(search(pattern, bm, text)?, vec![6, 14]);
-///     Ok(())
-/// }
+/// assert_eq!(occurrences, vec![6, 14]);
+/// # Ok(())
+/// # }
 /// ```
-pub struct BoyerMoore {
-    alpha_map: HashMap<char, usize>,
+pub struct BoyerMoore<T> {
+    pattern: Vec<T>,
+    alpha_map: HashMap<T, usize>,
     bad_char: Vec<Vec<usize>>,
     big_l: Vec<usize>,
     small_l_prime: Vec<usize>,
+    /// `N[i]` = length of the longest suffix of `pattern[..=i]` that is also a suffix of
+    /// `pattern`. Kept around (beyond its use in building `big_l`/`small_l_prime`) for
+    /// [`BoyerMoore::search_ag`].
+    n_arr: Vec<usize>,
+    /// Index into `pattern` of the rare-byte prefilter's guard symbol, set by
+    /// [`BoyerMoore::with_prefilter`]. `None` keeps the plain educational search path.
+    guard: Option<usize>,
+    /// Case-folding mode set by [`BoyerMoore::with_match_config`]. The pattern and alphabet
+    /// above are already folded accordingly; this is kept around so that searches can fold
+    /// incoming text the same way.
+    match_config: MatchConfig,
 }
 
-impl BoyerMoore {
-    /// Constructs a new `BoyerMoore` struct and initializes data structures.
+impl<T: Eq + Hash + Clone + Debug> BoyerMoore<T> {
+    /// Constructs a new `BoyerMoore` struct over an arbitrary symbol alphabet and initializes
+    /// data structures.
     ///
     /// # Errors
     ///
     /// Returns an error in the following situations:
     ///
-    /// - A character in the pattern is not found in the alphabet.
-    /// - The length of the pattern is not more than 1 character.
+    /// - A symbol in the pattern is not found in the alphabet.
+    /// - The length of the pattern is not more than 1 symbol.
     ///
     /// # Examples
     ///
@@ -258,23 +372,32 @@ impl BoyerMoore {
     /// # use anyhow::Result;
     ///
     /// # fn main() -> Result<()> {
-    /// let bm = BoyerMoore::new("ACTGTC", "ACGT")?;
+    /// let pattern: Vec<char> = "ACTGTC".chars().collect();
+    /// let alphabet: Vec<char> = "ACGT".chars().collect();
+    /// let bm = BoyerMoore::from_symbols(pattern, alphabet)?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn new(pattern: &str, alphabet: &str) -> Result<Self> {
+    pub fn from_symbols<P: Searchable<T>, A: Searchable<T>>(pattern: P, alphabet: A) -> Result<Self> {
         let mut alpha_map = HashMap::new();
-        for (i, c) in alphabet.chars().enumerate() {
-            alpha_map.insert(c, i);
+        for (i, symbol) in alphabet.iter().enumerate() {
+            alpha_map.insert(symbol, i);
         }
-        let bad_char = dense_bad_char_table(pattern, &alpha_map)?;
-        let (_, big_l, small_l_prime) = good_suffix_table(pattern)?;
+
+        let pattern: Vec<T> = pattern.iter().collect();
+        let bad_char = dense_bad_char_table(&pattern, &alpha_map)?;
+        let n_arr = n_array(&pattern)?;
+        let (_, big_l, small_l_prime) = good_suffix_table(pattern.len(), &n_arr);
 
         Ok(Self {
+            pattern,
             alpha_map,
             bad_char,
             big_l,
             small_l_prime,
+            n_arr,
+            guard: None,
+            match_config: MatchConfig::default(),
         })
     }
 
@@ -284,7 +407,7 @@ impl BoyerMoore {
     ///
     /// Returns an error in the following situations:
     ///
-    /// - A character in the pattern is not found in the alphabet.
+    /// - `symbol` is not found in the alphabet.
     /// - `offset` is greater than or equal to the pattern length.
     ///
     /// # Examples
@@ -300,17 +423,17 @@ impl BoyerMoore {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn bad_char_rule(&self, offset: usize, character: char) -> Result<usize> {
+    pub fn bad_char_rule(&self, offset: usize, symbol: T) -> Result<usize> {
         ensure!(
-            self.alpha_map.contains_key(&character),
-            format!("{} not found in alphabet", character)
+            self.alpha_map.contains_key(&symbol),
+            format!("{:?} not found in alphabet", symbol)
         );
         ensure!(
             offset < self.bad_char.len(),
             format!("Invalid offset {}", offset)
         );
 
-        let &index = self.alpha_map.get(&character).unwrap();
+        let &index = self.alpha_map.get(&symbol).unwrap();
         Ok((offset as isize - (self.bad_char[offset][index] as isize - 1)) as usize)
     }
 
@@ -368,4 +491,659 @@ impl BoyerMoore {
     pub fn match_skip(&self) -> usize {
         self.small_l_prime.len() - self.small_l_prime[1]
     }
+
+    /// Searches `text` for all occurrences of the pattern and collects their starting offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search compares the pattern against a symbol of `text` that is not
+    /// present in the alphabet the pattern was constructed with. Symbols that the shifts skip over
+    /// are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use boyer_moore::BoyerMoore;
+    /// # use anyhow::Result;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let bm = BoyerMoore::new("TCTA", "ACGT")?;
+    /// let occurrences = bm.find_all("GCTAGCTCTACGAGTCTA")?;
+    /// assert_eq!(occurrences, vec![6, 14]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_all<S: Searchable<T>>(&self, text: S) -> Result<Vec<usize>> {
+        self.find_iter(text).collect()
+    }
+
+    /// Returns a lazy iterator over the starting offsets of the pattern's occurrences in `text`.
+    ///
+    /// Unlike [`BoyerMoore::find_all`], offsets are produced one alignment at a time, so the
+    /// search can be short-circuited without scanning the rest of `text`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use boyer_moore::BoyerMoore;
+    /// # use anyhow::Result;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let bm = BoyerMoore::new("TCTA", "ACGT")?;
+    /// let first = bm.find_iter("GCTAGCTCTACGAGTCTA").next().transpose()?;
+    /// assert_eq!(first, Some(6));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_iter<S: Searchable<T>>(&self, text: S) -> FindIter<'_, T> {
+        FindIter {
+            bm: self,
+            text: text.iter().collect(),
+            i: 0,
+            done: false,
+        }
+    }
+
+    /// Searches `text` using the Apostolico-Giancarlo extension, which gives a worst-case linear
+    /// number of symbol comparisons (the plain `find_all`/`find_iter` path can be quadratic).
+    ///
+    /// The extension keeps an array `M`, indexed by text position, recording the length of the
+    /// pattern suffix already confirmed to match ending at that position by a previous
+    /// alignment. When the right-to-left scan reaches a text position with a nonzero `M` entry,
+    /// that entry is checked against `N[j]` (the precomputed length of the longest suffix of
+    /// `pattern[..=j]` that is also a suffix of `pattern`): if `N[j]` is no larger than the known
+    /// match, the whole block is known to match and is skipped without comparing symbols; if it
+    /// is larger, only the known-equal portion is skipped, and the scan resumes with ordinary
+    /// comparisons from there.
+    ///
+    /// Returns the matched offsets alongside the number of symbol comparisons performed, so
+    /// callers can contrast it with the plain algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search compares the pattern against a symbol of `text` that is not
+    /// present in the alphabet the pattern was constructed with. Symbols that are skipped over
+    /// are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use boyer_moore::BoyerMoore;
+    /// # use anyhow::Result;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let bm = BoyerMoore::new("TCTA", "ACGT")?;
+    /// let (occurrences, _comparisons) = bm.search_ag("GCTAGCTCTACGAGTCTA")?;
+    /// assert_eq!(occurrences, vec![6, 14]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_ag<S: Searchable<T>>(&self, text: S) -> Result<(Vec<usize>, usize)> {
+        let text: Vec<T> = text.iter().collect();
+        let pattern_len = self.pattern.len();
+
+        let mut occurrences = Vec::new();
+        let mut comparisons = 0_usize;
+
+        if pattern_len > text.len() {
+            return Ok((occurrences, comparisons));
+        }
+
+        // M[k]: length of the pattern suffix confirmed to match ending at text position k,
+        // established by a previous alignment.
+        let mut m_arr = vec![0_usize; text.len()];
+        let mut i = 0_usize;
+
+        while i <= text.len() - pattern_len {
+            let mut r = 0_usize;
+            let mut matched = 0_usize;
+            let mut mismatch_at = None;
+
+            while r < pattern_len {
+                let j = pattern_len - 1 - r;
+                let k = i + j;
+                let known = m_arr[k];
+
+                if known > 0 {
+                    // `N[j]` bounds how much of `pattern[..=j]` could possibly be subsumed by the
+                    // already-known match; skip that much without comparing. If `N[j]` is 0 there
+                    // is nothing to skip, so fall through to an ordinary comparison at `j` instead
+                    // of looping forever.
+                    let advance = known.min(self.n_arr[j]);
+                    if advance > 0 {
+                        r += advance;
+                        matched += advance;
+                        continue;
+                    }
+                }
+
+                comparisons += 1;
+                if self.pattern[j] != text[i + j] {
+                    mismatch_at = Some(j);
+                    break;
+                }
+
+                matched += 1;
+                r += 1;
+            }
+
+            m_arr[i + pattern_len - 1] = matched;
+
+            let shift = if let Some(j) = mismatch_at {
+                let skip_bc = self.bad_char_rule(j, text[i + j].clone())?;
+                let skip_gs = self.good_suffix_rule(j)?;
+                *[1, skip_bc, skip_gs].iter().max().unwrap()
+            } else {
+                occurrences.push(i);
+                let skip_gs = self.match_skip();
+                *[1, skip_gs].iter().max().unwrap()
+            };
+
+            i += shift;
+        }
+
+        Ok((occurrences, comparisons))
+    }
+}
+
+impl BoyerMoore<char> {
+    /// Constructs a new `BoyerMoore` struct over `char`s and initializes data structures.
+    ///
+    /// Patterns and alphabets are consumed as full Unicode scalar values, not bytes, so
+    /// multi-byte UTF-8 text is matched correctly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the following situations:
+    ///
+    /// - A character in the pattern is not found in the alphabet.
+    /// - The length of the pattern is not more than 1 character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use boyer_moore::BoyerMoore;
+    /// # use anyhow::Result;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let bm = BoyerMoore::new("ACTGTC", "ACGT")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(pattern: &str, alphabet: &str) -> Result<Self> {
+        Self::from_symbols(pattern, alphabet)
+    }
+
+    /// Constructs a new `BoyerMoore` struct over `char`s, folding the pattern and alphabet
+    /// through `config` before building the bad-character and good-suffix tables.
+    ///
+    /// [`BoyerMoore::find_all_folded`] and [`BoyerMoore::find_iter_folded`] fold their input text
+    /// through the same `config`, so e.g. with [`MatchConfig::AsciiCaseInsensitive`] the pattern
+    /// `"cat"` also matches `"Cat"` and `"CAT"`. [`BoyerMoore::new`] is equivalent to this with
+    /// [`MatchConfig::CaseSensitive`], and its behavior is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the following situations:
+    ///
+    /// - A character in the pattern is not found in the alphabet, once both are folded.
+    /// - The length of the pattern is not more than 1 character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use boyer_moore::{BoyerMoore, MatchConfig};
+    /// # use anyhow::Result;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let alphabet = "abcdefghijklmnopqrstuvwxyz ";
+    /// let bm = BoyerMoore::with_match_config("cat", alphabet, MatchConfig::AsciiCaseInsensitive)?;
+    /// let occurrences = bm.find_all_folded("The Cat sat on the mat CAT")?;
+    /// assert_eq!(occurrences, vec![4, 23]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_match_config(pattern: &str, alphabet: &str, config: MatchConfig) -> Result<Self> {
+        let folded_pattern: String = pattern.chars().map(|c| config.fold(c)).collect();
+        let folded_alphabet: String = alphabet.chars().map(|c| config.fold(c)).collect();
+
+        let mut bm = Self::from_symbols(folded_pattern.as_str(), folded_alphabet.as_str())?;
+        bm.match_config = config;
+        Ok(bm)
+    }
+
+    /// Searches `text` for all occurrences of the pattern, folding `text` through the
+    /// [`MatchConfig`] this searcher was built with (see [`BoyerMoore::with_match_config`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if, once folded, the search compares the pattern against a character of
+    /// `text` that is not present in the (also folded) alphabet the pattern was constructed with.
+    /// Characters that the shifts skip over are not checked.
+    pub fn find_all_folded(&self, text: &str) -> Result<Vec<usize>> {
+        self.find_iter_folded(text).collect()
+    }
+
+    /// Returns a lazy iterator over the starting offsets of the pattern's occurrences in `text`,
+    /// folding `text` through the [`MatchConfig`] this searcher was built with (see
+    /// [`BoyerMoore::with_match_config`]).
+    pub fn find_iter_folded(&self, text: &str) -> FindIter<'_, char> {
+        let folded: String = text.chars().map(|c| self.match_config.fold(c)).collect();
+        self.find_iter(folded.as_str())
+    }
+
+    /// Searches a reader for all occurrences of the pattern, without requiring the whole stream
+    /// to fit in memory.
+    ///
+    /// A rolling buffer at least as long as the pattern is filled from `reader` in chunks. Each
+    /// refill preserves the trailing `pattern.len() - 1` characters of the previous buffer, so
+    /// occurrences that straddle a chunk boundary are still found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from `reader` fails, if the stream does not contain valid
+    /// UTF-8, or if the search compares the pattern against a character of the stream that is not
+    /// present in the alphabet the pattern was constructed with. Characters that the shifts skip
+    /// over are not checked.
+    pub fn find_in_reader<R: Read>(&self, mut reader: R) -> Result<Vec<usize>> {
+        const CHUNK_SIZE: usize = 64 * 1024;
+
+        let pattern_len = self.pattern.len();
+        ensure!(pattern_len > 0, "Pattern must not be empty");
+
+        let mut occurrences = Vec::new();
+        let mut buf = String::new();
+        // Raw bytes read but not yet decoded: either empty, or a UTF-8 sequence truncated by a
+        // chunk boundary, carried over until enough bytes arrive to complete it.
+        let mut pending: Vec<u8> = Vec::new();
+        let mut base_offset = 0_usize;
+        let mut chunk = vec![0_u8; CHUNK_SIZE.max(pattern_len)];
+        let mut eof = false;
+
+        loop {
+            if !eof {
+                let n = reader
+                    .read(&mut chunk)
+                    .with_context(|| "Failed to read from reader")?;
+                if n == 0 {
+                    eof = true;
+                } else {
+                    pending.extend_from_slice(&chunk[..n]);
+
+                    let valid_up_to = match from_utf8(&pending) {
+                        Ok(text) => {
+                            buf.push_str(text);
+                            pending.len()
+                        }
+                        // A sequence truncated by this chunk's boundary, not an invalid one:
+                        // decode what's complete and leave the rest in `pending` for next time.
+                        Err(err) if err.error_len().is_none() => {
+                            let valid_up_to = err.valid_up_to();
+                            buf.push_str(from_utf8(&pending[..valid_up_to]).unwrap());
+                            valid_up_to
+                        }
+                        Err(err) => {
+                            return Err(err).with_context(|| "Reader did not yield valid UTF-8");
+                        }
+                    };
+                    pending.drain(..valid_up_to);
+                }
+            }
+
+            if eof {
+                ensure!(pending.is_empty(), "Reader did not yield valid UTF-8");
+            }
+
+            let buf_len = buf.chars().count();
+            if buf_len < pattern_len {
+                if eof {
+                    break;
+                }
+                continue;
+            }
+
+            // Offsets at or past `safe_len` might still grow into a different match once more
+            // input arrives, so only report offsets before it; the rest are re-examined after
+            // the window slides forward. Once the reader is exhausted, every offset is final.
+            let safe_len = if eof { buf_len } else { buf_len - (pattern_len - 1) };
+
+            for result in self.find_iter(buf.as_str()) {
+                let offset = result?;
+                if offset < safe_len {
+                    occurrences.push(base_offset + offset);
+                }
+            }
+
+            if eof {
+                break;
+            }
+
+            let keep_from = safe_len;
+            base_offset += keep_from;
+            buf = buf.chars().skip(keep_from).collect();
+        }
+
+        Ok(occurrences)
+    }
+}
+
+impl BoyerMoore<u8> {
+    /// Enables a rare-byte prefilter for this pattern.
+    ///
+    /// Picks the pattern byte with the lowest entry in [`BYTE_FREQUENCY`] as a "guard" byte.
+    /// Subsequent calls to [`BoyerMoore::find_all_with_prefilter`] use `memchr` to jump straight
+    /// to the next text position where the guard byte could possibly align, instead of
+    /// inspecting every alignment in turn. This preserves exact Boyer-Moore match semantics and
+    /// is typically much faster on long, natural-language or DNA-like input; the plain
+    /// `find_all`/`find_iter` path is left untouched for the step-by-step educational trace.
+    #[must_use]
+    pub fn with_prefilter(mut self) -> Self {
+        self.guard = self
+            .pattern
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, &byte)| BYTE_FREQUENCY[byte as usize])
+            .map(|(i, _)| i);
+        self
+    }
+
+    /// Searches `text` for all occurrences of the pattern.
+    ///
+    /// If [`BoyerMoore::with_prefilter`] was used to build this searcher, `memchr` is used to
+    /// skip directly to the next alignment whose guard byte could match before running the
+    /// bad-character/good-suffix verification there; otherwise this falls back to the same
+    /// alignment-by-alignment search as [`BoyerMoore::find_all`]. Either way, the reported
+    /// matches are identical.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search compares the pattern against a byte of `text` that is not
+    /// present in the alphabet the pattern was constructed with. Bytes that the shifts or the
+    /// `memchr` jumps skip over are not checked.
+    pub fn find_all_with_prefilter(&self, text: &[u8]) -> Result<Vec<usize>> {
+        let Some(g) = self.guard else {
+            return self.find_all(text);
+        };
+
+        let pattern = &self.pattern;
+        let guard_byte = pattern[g];
+        let mut occurrences = Vec::new();
+
+        if pattern.len() > text.len() {
+            return Ok(occurrences);
+        }
+
+        let mut i = 0_usize;
+        let last_alignment = text.len() - pattern.len();
+
+        while i <= last_alignment {
+            let window = &text[i + g..last_alignment + g + 1];
+            let Some(found) = memchr(guard_byte, window) else {
+                break;
+            };
+            i += found;
+
+            let mut shift = 1;
+            let mut mismatched = false;
+
+            for j in (0..pattern.len()).rev() {
+                if pattern[j] != text[i + j] {
+                    let skip_bc = self.bad_char_rule(j, text[i + j])?;
+                    let skip_gs = self.good_suffix_rule(j)?;
+                    shift = *[shift, skip_bc, skip_gs].iter().max().unwrap();
+                    mismatched = true;
+                    break;
+                }
+            }
+
+            if !mismatched {
+                occurrences.push(i);
+                let skip_gs = self.match_skip();
+                shift = *[shift, skip_gs].iter().max().unwrap();
+            }
+
+            i += shift;
+        }
+
+        Ok(occurrences)
+    }
+}
+
+/// Lazy iterator over the starting offsets of a pattern's occurrences in a text, returned by
+/// [`BoyerMoore::find_iter`].
+pub struct FindIter<'a, T> {
+    bm: &'a BoyerMoore<T>,
+    text: Vec<T>,
+    i: usize,
+    done: bool,
+}
+
+impl<'a, T: Eq + Hash + Clone + Debug> Iterator for FindIter<'a, T> {
+    type Item = Result<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let pattern = &self.bm.pattern;
+        if pattern.len() > self.text.len() {
+            self.done = true;
+            return None;
+        }
+
+        while self.i < self.text.len() - pattern.len() + 1 {
+            let mut shift = 1;
+            let mut mismatched = false;
+
+            for j in (0..pattern.len()).rev() {
+                if pattern[j] != self.text[self.i + j] {
+                    let skip_bc = match self.bm.bad_char_rule(j, self.text[self.i + j].clone()) {
+                        Ok(skip) => skip,
+                        Err(err) => {
+                            self.done = true;
+                            return Some(Err(err));
+                        }
+                    };
+                    let skip_gs = match self.bm.good_suffix_rule(j) {
+                        Ok(skip) => skip,
+                        Err(err) => {
+                            self.done = true;
+                            return Some(Err(err));
+                        }
+                    };
+                    shift = *[shift, skip_bc, skip_gs].iter().max().unwrap();
+                    mismatched = true;
+                    break;
+                }
+            }
+
+            let found = if mismatched {
+                None
+            } else {
+                let skip_gs = self.bm.match_skip();
+                shift = *[shift, skip_gs].iter().max().unwrap();
+                Some(self.i)
+            };
+
+            self.i += shift;
+
+            if let Some(offset) = found {
+                return Some(Ok(offset));
+            }
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+/// Per-pattern alignment/comparison counters collected while searching with a
+/// [`BoyerMooreSet`], so callers (such as the visualizer) can report how much work each
+/// pattern's search did.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PatternStats {
+    pub alignments: usize,
+    pub comparisons: usize,
+}
+
+/// Searches a text for occurrences of any pattern in a set, over a shared `char` alphabet.
+///
+/// This is a straightforward combination of one [`BoyerMoore`] searcher per pattern: at each
+/// text position, every pattern that still fits is compared, and the cursor advances by the
+/// *smallest* of their shifts, so no pattern's occurrence can be skipped over. Patterns shorter
+/// than the longest one in the set keep being tried after the longer ones have run out of room
+/// at the end of the text. This does not share preprocessing across patterns the way a
+/// Commentz-Walter-style combined trie would, so it is not as fast as it could be on large
+/// pattern sets, but it reuses the existing single-pattern machinery directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use boyer_moore::BoyerMooreSet;
+/// # use anyhow::Result;
+///
+/// # fn main() -> Result<()> {
+/// let set = BoyerMooreSet::new(&["TCTA", "GCTC"], "ACGT")?;
+/// let matches = set.find_all("GCTAGCTCTACGAGTCTA")?;
+///
+/// assert_eq!(matches, vec![(1, 4), (0, 6), (0, 14)]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct BoyerMooreSet {
+    patterns: Vec<BoyerMoore<char>>,
+    min_pattern_len: usize,
+}
+
+impl BoyerMooreSet {
+    /// Constructs a new `BoyerMooreSet` from a list of patterns sharing an alphabet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the following situations:
+    ///
+    /// - `patterns` is empty.
+    /// - A character in one of the patterns is not found in the alphabet.
+    /// - The length of one of the patterns is not more than 1 character.
+    pub fn new(patterns: &[&str], alphabet: &str) -> Result<Self> {
+        ensure!(
+            !patterns.is_empty(),
+            "`patterns` must contain at least one pattern"
+        );
+
+        let patterns: Vec<BoyerMoore<char>> = patterns
+            .iter()
+            .map(|pattern| BoyerMoore::new(pattern, alphabet))
+            .collect::<Result<_>>()?;
+        let min_pattern_len = patterns.iter().map(|bm| bm.pattern.len()).min().unwrap();
+
+        Ok(Self {
+            patterns,
+            min_pattern_len,
+        })
+    }
+
+    /// Searches `text` for all occurrences of any pattern in the set and collects them as
+    /// `(pattern_index, text_offset)` pairs, in the order they're found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search compares a pattern against a character of `text` that is
+    /// not present in the alphabet the set was constructed with. Characters that the shifts skip
+    /// over are not checked.
+    pub fn find_all(&self, text: &str) -> Result<Vec<(usize, usize)>> {
+        self.find_iter(text).collect()
+    }
+
+    /// Returns a lazy iterator over `(pattern_index, text_offset)` matches of any pattern in the
+    /// set against `text`.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> BoyerMooreSetIter<'a> {
+        let stats = vec![PatternStats::default(); self.patterns.len()];
+
+        BoyerMooreSetIter {
+            set: self,
+            text: text.chars().collect(),
+            i: 0,
+            pending: VecDeque::new(),
+            stats,
+        }
+    }
+}
+
+/// Lazy iterator over `(pattern_index, text_offset)` matches, returned by
+/// [`BoyerMooreSet::find_iter`].
+pub struct BoyerMooreSetIter<'a> {
+    set: &'a BoyerMooreSet,
+    text: Vec<char>,
+    i: usize,
+    pending: VecDeque<(usize, usize)>,
+    stats: Vec<PatternStats>,
+}
+
+impl<'a> BoyerMooreSetIter<'a> {
+    /// Returns per-pattern alignment/comparison counts accumulated by the search so far.
+    #[must_use]
+    pub fn stats(&self) -> &[PatternStats] {
+        &self.stats
+    }
+}
+
+impl<'a> Iterator for BoyerMooreSetIter<'a> {
+    type Item = Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.pending.pop_front() {
+                return Some(Ok(found));
+            }
+
+            if self.i + self.set.min_pattern_len > self.text.len() {
+                return None;
+            }
+
+            let mut min_shift = None;
+
+            for (k, bm) in self.set.patterns.iter().enumerate() {
+                let pattern_len = bm.pattern.len();
+                if self.i + pattern_len > self.text.len() {
+                    continue;
+                }
+
+                self.stats[k].alignments += 1;
+
+                let mut shift = 1;
+                let mut mismatched = false;
+
+                for j in (0..pattern_len).rev() {
+                    self.stats[k].comparisons += 1;
+
+                    if bm.pattern[j] != self.text[self.i + j] {
+                        let skip_bc = match bm.bad_char_rule(j, self.text[self.i + j]) {
+                            Ok(skip) => skip,
+                            Err(err) => return Some(Err(err)),
+                        };
+                        let skip_gs = match bm.good_suffix_rule(j) {
+                            Ok(skip) => skip,
+                            Err(err) => return Some(Err(err)),
+                        };
+                        shift = *[shift, skip_bc, skip_gs].iter().max().unwrap();
+                        mismatched = true;
+                        break;
+                    }
+                }
+
+                if mismatched {
+                    min_shift = Some(min_shift.map_or(shift, |m: usize| m.min(shift)));
+                } else {
+                    self.pending.push_back((k, self.i));
+                    let skip_gs = bm.match_skip();
+                    shift = *[shift, skip_gs].iter().max().unwrap();
+                    min_shift = Some(min_shift.map_or(shift, |m: usize| m.min(shift)));
+                }
+            }
+
+            self.i += min_shift.unwrap_or(1);
+        }
+    }
 }
@@ -1,6 +1,5 @@
 use std::{
     io::{self, Write},
-    str::from_utf8,
     thread, time,
 };
 
@@ -10,11 +9,20 @@ use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use boyer_moore::BoyerMoore;
 
+/// Collects a slice of `char`s into a `String` for display.
+fn chars_to_string(chars: &[char]) -> String {
+    chars.iter().collect()
+}
+
 /// Prints text and pattern with appropriate colors.
+///
+/// `text`/`pattern` are `char` slices, and `t_off`/`p_off` are `char` offsets into them, so that
+/// multi-byte UTF-8 input is sliced correctly (a `&str` byte-sliced by a `char` offset would
+/// panic on a non-ASCII input with "byte index is not a char boundary").
 fn visualize(
     mut stdout: &mut StandardStream,
-    text: &str,
-    pattern: &str,
+    text: &[char],
+    pattern: &[char],
     t_off: usize,
     p_off: usize,
     matched: bool,
@@ -26,29 +34,55 @@ fn visualize(
 
     for i in (p_off..pattern.len()).rev() {
         if matched || i > p_off {
-            write!(&mut stdout, "{}", &text[..t_off + i])?;
+            write!(&mut stdout, "{}", chars_to_string(&text[..t_off + i]))?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Green)))?;
-            write!(&mut stdout, "{}", &pattern[i..])?;
+            write!(&mut stdout, "{}", chars_to_string(&pattern[i..]))?;
             stdout.reset()?;
-            writeln!(&mut stdout, "{}", &text[t_off + pattern.len()..])?;
-
-            write!(&mut stdout, "{}{}", " ".repeat(t_off), &pattern[..i],)?;
+            writeln!(
+                &mut stdout,
+                "{}",
+                chars_to_string(&text[t_off + pattern.len()..])
+            )?;
+
+            write!(
+                &mut stdout,
+                "{}{}",
+                " ".repeat(t_off),
+                chars_to_string(&pattern[..i]),
+            )?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Green)))?;
-            writeln!(&mut stdout, "{}", &pattern[i..])?;
+            writeln!(&mut stdout, "{}", chars_to_string(&pattern[i..]))?;
         } else {
-            write!(&mut stdout, "{}", &text[..t_off + p_off])?;
+            write!(&mut stdout, "{}", chars_to_string(&text[..t_off + p_off]))?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Red)))?;
-            write!(&mut stdout, "{}", &text[t_off + p_off..t_off + p_off + 1])?;
+            write!(
+                &mut stdout,
+                "{}",
+                chars_to_string(&text[t_off + p_off..t_off + p_off + 1])
+            )?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Green)))?;
-            write!(&mut stdout, "{}", &pattern[p_off + 1..])?;
+            write!(&mut stdout, "{}", chars_to_string(&pattern[p_off + 1..]))?;
             stdout.reset()?;
-            writeln!(&mut stdout, "{}", &text[t_off + pattern.len()..])?;
-
-            write!(&mut stdout, "{}{}", " ".repeat(t_off), &pattern[..p_off],)?;
+            writeln!(
+                &mut stdout,
+                "{}",
+                chars_to_string(&text[t_off + pattern.len()..])
+            )?;
+
+            write!(
+                &mut stdout,
+                "{}{}",
+                " ".repeat(t_off),
+                chars_to_string(&pattern[..p_off]),
+            )?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Red)))?;
-            write!(&mut stdout, "{}", &pattern[p_off..p_off + 1])?;
+            write!(
+                &mut stdout,
+                "{}",
+                chars_to_string(&pattern[p_off..p_off + 1])
+            )?;
             stdout.set_color(spec.set_bold(true).set_fg(Some(Color::Green)))?;
-            writeln!(&mut stdout, "{}", &pattern[p_off + 1..])?;
+            writeln!(&mut stdout, "{}", chars_to_string(&pattern[p_off + 1..]))?;
         }
         stdout.reset()?;
 
@@ -74,9 +108,15 @@ fn visualize(
 }
 
 /// Searches for all occurrences of `pattern` in `text`.
+///
+/// This drives the same alignment-by-alignment algorithm as [`BoyerMoore::find_iter`], but
+/// doesn't delegate to it: the step-by-step visualization needs the mismatch index and the
+/// bad-character/good-suffix skip amounts at every alignment, which `find_iter` doesn't surface
+/// (it only yields final match offsets). The loop here indexes `pattern`/`text` as `char`s (not
+/// bytes) so it handles multi-byte UTF-8 input correctly, same as the rest of the library.
 fn boyer_moore_search(
     pattern: &str,
-    bm: BoyerMoore,
+    bm: BoyerMoore<char>,
     text: &str,
     sleep_time: f32,
     stdout: &mut StandardStream,
@@ -85,11 +125,11 @@ fn boyer_moore_search(
     let mut alignments = 0;
     let mut comparisons = 0;
 
-    let pattern = pattern.as_bytes();
-    let text = text.as_bytes();
+    let pattern_chars: Vec<char> = pattern.chars().collect();
+    let text_chars: Vec<char> = text.chars().collect();
     let mut i = 0;
 
-    while i < text.len() - pattern.len() + 1 {
+    while i < text_chars.len() - pattern_chars.len() + 1 {
         let mut shift = 1;
         let mut mismatched = false;
         let mut mismatch_index = 0;
@@ -97,11 +137,11 @@ fn boyer_moore_search(
         let mut skip_gs = 0;
         alignments += 1;
 
-        for j in (0..pattern.len()).rev() {
+        for j in (0..pattern_chars.len()).rev() {
             comparisons += 1;
 
-            if pattern[j] != text[i + j] {
-                skip_bc = bm.bad_char_rule(j, text[i + j] as char)?;
+            if pattern_chars[j] != text_chars[i + j] {
+                skip_bc = bm.bad_char_rule(j, text_chars[i + j])?;
                 skip_gs = bm.good_suffix_rule(j)?;
                 shift = *[shift, skip_bc, skip_gs].iter().max().unwrap();
                 mismatched = true;
@@ -118,8 +158,8 @@ fn boyer_moore_search(
 
         visualize(
             stdout,
-            from_utf8(text)?,
-            from_utf8(pattern)?,
+            &text_chars,
+            &pattern_chars,
             i,
             mismatch_index,
             !mismatched,
@@ -127,7 +167,7 @@ fn boyer_moore_search(
         )?;
         println!("Comparisons: {}", comparisons);
 
-        if i < text.len() - pattern.len() {
+        if i < text_chars.len() - pattern_chars.len() {
             if skip_bc > 0 {
                 println!("Bad character shift: {}", skip_bc);
             }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use boyer_moore::BoyerMoore;
+use boyer_moore::{BoyerMoore, BoyerMooreSet, MatchConfig};
 use pretty_assertions::assert_eq;
 
 const ALPHABET: &str = "ACGT";
@@ -25,7 +25,7 @@ fn match_skip() {
     assert_eq!(bm.match_skip(), 2);
 }
 
-fn boyer_moore_search(pattern: &str, bm: BoyerMoore, text: &str) -> Result<Vec<usize>> {
+fn boyer_moore_search(pattern: &str, bm: BoyerMoore<char>, text: &str) -> Result<Vec<usize>> {
     let mut occurrences = Vec::new();
 
     let pattern = pattern.as_bytes();
@@ -66,3 +66,222 @@ fn search() {
 
     assert_eq!(boyer_moore_search(pattern, bm, text).unwrap(), vec![6, 14]);
 }
+
+#[test]
+fn find_all() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    assert_eq!(bm.find_all(text).unwrap(), vec![6, 14]);
+}
+
+#[test]
+fn find_iter() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    let occurrences: Result<Vec<usize>> = bm.find_iter(text).collect();
+    assert_eq!(occurrences.unwrap(), vec![6, 14]);
+}
+
+#[test]
+fn find_in_reader() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    assert_eq!(
+        bm.find_in_reader(text.as_bytes()).unwrap(),
+        vec![6, 14]
+    );
+}
+
+#[test]
+fn find_all_with_prefilter() {
+    let text = b"GCTAGCTCTACGAGTCTA".as_slice();
+    let pattern = b"TCTA".as_slice();
+    let alphabet = ALPHABET.as_bytes();
+    let bm = BoyerMoore::from_symbols(pattern, alphabet)
+        .unwrap()
+        .with_prefilter();
+
+    assert_eq!(bm.find_all_with_prefilter(text).unwrap(), vec![6, 14]);
+}
+
+#[test]
+fn find_all_with_prefilter_matches_find_all() {
+    // The prefiltered search must agree with the plain search on a longer, less regular text.
+    let text = b"ACGTACGTTCAAGGCATCAATCAACTGACGATCAAGGCTACGATCAA".as_slice();
+    let pattern = b"TCAA".as_slice();
+    let alphabet = ALPHABET.as_bytes();
+    let bm = BoyerMoore::from_symbols(pattern, alphabet).unwrap();
+    let bm_prefiltered = BoyerMoore::from_symbols(pattern, alphabet)
+        .unwrap()
+        .with_prefilter();
+
+    assert_eq!(
+        bm_prefiltered.find_all_with_prefilter(text).unwrap(),
+        bm.find_all(text).unwrap()
+    );
+}
+
+#[test]
+fn search_ag() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    let (occurrences, _comparisons) = bm.search_ag(text).unwrap();
+    assert_eq!(occurrences, vec![6, 14]);
+}
+
+#[test]
+fn search_ag_matches_find_all() {
+    // The Apostolico-Giancarlo extension must find exactly the same occurrences as the plain
+    // search, on a text with overlapping and repetitive structure likely to exercise the
+    // subsumed/partial-overlap skip logic.
+    let text = "ACGTACGTTCAAGGCATCAATCAACTGACGATCAAGGCTACGATCAA";
+    let pattern = "TCAA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    let (ag_occurrences, _comparisons) = bm.search_ag(text).unwrap();
+    assert_eq!(ag_occurrences, bm.find_all(text).unwrap());
+}
+
+#[test]
+fn search_ag_does_not_hang_when_n_is_zero_at_a_known_position() {
+    // Regression test: when the subsumption fast-path's `M`/`N` lookup agrees on a known match
+    // but `N[j] == 0`, the scan must fall through to an ordinary comparison instead of spinning
+    // forever with no progress.
+    let pattern = "ABAAB";
+    let alphabet = "AB";
+    let text = "AAAABABBBABBBBBAABBBBB";
+    let bm = BoyerMoore::new(pattern, alphabet).unwrap();
+
+    let (ag_occurrences, _comparisons) = bm.search_ag(text).unwrap();
+    assert_eq!(ag_occurrences, bm.find_all(text).unwrap());
+}
+
+#[test]
+fn boyer_moore_set_find_all() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let set = BoyerMooreSet::new(&["TCTA", "GCTC"], ALPHABET).unwrap();
+
+    assert_eq!(set.find_all(text).unwrap(), vec![(1, 4), (0, 6), (0, 14)]);
+}
+
+#[test]
+fn boyer_moore_set_tracks_per_pattern_stats() {
+    let text = "GCTAGCTCTACGAGTCTA";
+    let set = BoyerMooreSet::new(&["TCTA", "GCTC"], ALPHABET).unwrap();
+
+    let mut search = set.find_iter(text);
+    for result in &mut search {
+        result.unwrap();
+    }
+
+    let stats = search.stats();
+    assert_eq!(stats.len(), 2);
+    assert!(stats.iter().all(|s| s.alignments > 0 && s.comparisons > 0));
+}
+
+#[test]
+fn find_all_folded_ascii_case_insensitive() {
+    let text = "The Cat sat on the mat, CAT!";
+    let pattern = "cat";
+    let alphabet = "abcdefghijklmnopqrstuvwxyz ,!";
+    let bm = BoyerMoore::with_match_config(pattern, alphabet, MatchConfig::AsciiCaseInsensitive)
+        .unwrap();
+
+    assert_eq!(bm.find_all_folded(text).unwrap(), vec![4, 24]);
+}
+
+#[test]
+fn find_all_folded_unicode_case_fold() {
+    let text = "RÉSUMÉ résumé";
+    let pattern = "résumé";
+    let alphabet = "abcdefghijklmnopqrstuvwxyzé ";
+    let bm = BoyerMoore::with_match_config(pattern, alphabet, MatchConfig::UnicodeCaseFold)
+        .unwrap();
+
+    assert_eq!(bm.find_all_folded(text).unwrap(), vec![0, 7]);
+}
+
+#[test]
+fn find_all_folded_unicode_case_fold_leaves_turkish_dotted_i_unmapped() {
+    // 'İ' (U+0130) has no single-character simple case fold (its full lowercase mapping is the
+    // two-character "i̇"), so folding must leave it unchanged rather than silently dropping the
+    // combining dot above and mapping it to plain 'i'.
+    let text = "İstanbul";
+    let pattern = "İstanbul";
+    let alphabet = "stanbulİ";
+    let bm = BoyerMoore::with_match_config(pattern, alphabet, MatchConfig::UnicodeCaseFold)
+        .unwrap();
+
+    assert_eq!(bm.find_all_folded(text).unwrap(), vec![0]);
+}
+
+#[test]
+fn with_match_config_case_sensitive_matches_new() {
+    // `MatchConfig::CaseSensitive` must leave matching behavior identical to `BoyerMoore::new`.
+    let text = "GCTAGCTCTACGAGTCTA";
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+    let bm_folded =
+        BoyerMoore::with_match_config(pattern, ALPHABET, MatchConfig::CaseSensitive).unwrap();
+
+    assert_eq!(
+        bm_folded.find_all_folded(text).unwrap(),
+        bm.find_all(text).unwrap()
+    );
+}
+
+/// A reader that only ever returns a handful of bytes per call, so tests can exercise the
+/// rolling-buffer refill logic in `find_in_reader` instead of reading everything in one shot.
+struct ChunkedReader<'a> {
+    remaining: &'a [u8],
+    chunk_len: usize,
+}
+
+impl std::io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.chunk_len.min(self.remaining.len()).min(buf.len());
+        buf[..n].copy_from_slice(&self.remaining[..n]);
+        self.remaining = &self.remaining[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn find_in_reader_match_across_chunk_boundary() {
+    // A pattern occurrence that straddles wherever the rolling buffer happens to refill must
+    // still be reported exactly once.
+    let text = "A".repeat(100) + "TCTA" + &"A".repeat(100);
+    let pattern = "TCTA";
+    let bm = BoyerMoore::new(pattern, ALPHABET).unwrap();
+
+    let reader = ChunkedReader {
+        remaining: text.as_bytes(),
+        chunk_len: 3,
+    };
+    assert_eq!(bm.find_in_reader(reader).unwrap(), vec![100]);
+}
+
+#[test]
+fn find_in_reader_multi_byte_char_split_across_chunk_boundary() {
+    // A reader that returns a single byte per call will, for non-ASCII text, hand back chunks
+    // that split a multi-byte UTF-8 character down the middle. That must not be mistaken for
+    // invalid UTF-8.
+    let text = "café résumé";
+    let pattern = "é ";
+    let alphabet = "abcdefgimnrstué ";
+    let bm = BoyerMoore::new(pattern, alphabet).unwrap();
+
+    let reader = ChunkedReader {
+        remaining: text.as_bytes(),
+        chunk_len: 1,
+    };
+    assert_eq!(bm.find_in_reader(reader).unwrap(), vec![3]);
+}